@@ -1,7 +1,11 @@
 use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::native_token::lamports_to_sol;
 
 use crate::{
-    components::{BackButton, OreIcon, Spinner},
+    components::{
+        wallet_adapter::{estimated_fee_lamports, priority_fee_micro_lamports, FeePreset},
+        BackButton, OreIcon, Spinner,
+    },
     hooks::use_gateway,
 };
 
@@ -24,6 +28,17 @@ pub fn ClaimConfirm<'a>(cx: Scope<'a, ClaimConfirmProps<'a>>) -> Element {
     let claim_step = cx.props.claim_step;
     let amountf = (amount as f64) / 10f64.powf(ore::TOKEN_DECIMALS.into());
     let gateway = use_gateway(cx);
+    let fee_preset = use_state(cx, || FeePreset::Normal);
+    let compute_unit_price = use_state(cx, || 0u64);
+
+    use_future(cx, (fee_preset,), |(fee_preset,)| {
+        let gateway = gateway.clone();
+        let compute_unit_price = compute_unit_price.clone();
+        async move {
+            let price = priority_fee_micro_lamports(&gateway, *fee_preset.get()).await;
+            compute_unit_price.set(price);
+        }
+    });
 
     render! {
         div {
@@ -59,6 +74,27 @@ pub fn ClaimConfirm<'a>(cx: Scope<'a, ClaimConfirmProps<'a>>) -> Element {
                         "{amountf}"
                     }
                 }
+                div {
+                    class: "flex flex-col gap-2",
+                    p {
+                        class: "text-sm font-semibold",
+                        "Priority fee"
+                    }
+                    div {
+                        class: "flex flex-row gap-2",
+                        for preset in FeePreset::ALL {
+                            button {
+                                class: if *fee_preset.get() == preset { "flex-1 py-2 rounded bg-green-500 text-white text-sm font-semibold" } else { "flex-1 py-2 rounded bg-gray-100 dark:bg-gray-900 text-sm font-semibold" },
+                                onclick: move |_| fee_preset.set(preset),
+                                "{preset.label()}"
+                            }
+                        }
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "Estimated fee: {lamports_to_sol(estimated_fee_lamports(*compute_unit_price.get()))} SOL"
+                    }
+                }
             }
             div {
                 class: "flex flex-col sm:flex-row gap-2",
@@ -72,8 +108,9 @@ pub fn ClaimConfirm<'a>(cx: Scope<'a, ClaimConfirmProps<'a>>) -> Element {
                         let claim_step = claim_step.clone();
                         let is_busy = is_busy.clone();
                         let gateway = gateway.clone();
+                        let compute_unit_price = *compute_unit_price.get();
                         cx.spawn(async move {
-                            match gateway.claim_ore(amount).await {
+                            match gateway.claim_ore(amount, compute_unit_price).await {
                                 Ok(_sig) => {
                                     is_busy.set(false);
                                     // balance_.restart();
@@ -1,8 +1,13 @@
+use bip39::{Language, Mnemonic};
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
 use solana_client_wasm::solana_sdk::{
     bs58,
     native_token::{lamports_to_sol, LAMPORTS_PER_SOL},
+    pubkey::Pubkey,
     signature::Keypair,
     signer::Signer,
 };
@@ -15,6 +20,64 @@ use crate::{
     route::Route,
 };
 
+/// Solana's default derivation path, per the SLIP-0010 ed25519 scheme used by
+/// the official CLI and web wallets (`m/44'/501'/0'/0'`).
+const DEFAULT_DERIVATION_PATH: [u32; 4] = [44, 501, 0, 0];
+
+/// Derives the ed25519 `Keypair` Solana wallets produce from a BIP39 mnemonic,
+/// following SLIP-0010 fully-hardened derivation along `path`.
+///
+/// Returns `None` if the mnemonic fails BIP39 wordlist/checksum validation.
+fn keypair_from_mnemonic(phrase: &str, passphrase: &str, path: &[u32]) -> Option<Keypair> {
+    let mnemonic = Mnemonic::parse_in_normalized(Language::English, phrase.trim()).ok()?;
+    let seed = mnemonic.to_seed_normalized(passphrase);
+
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"ed25519 seed").ok()?;
+    mac.update(&seed);
+    let i = mac.finalize().into_bytes();
+    let (mut key, mut chain_code) = (i[..32].to_vec(), i[32..].to_vec());
+
+    for &index in path {
+        let mut mac = Hmac::<Sha512>::new_from_slice(&chain_code).ok()?;
+        mac.update(&[0u8]);
+        mac.update(&key);
+        mac.update(&(index | 0x80000000).to_be_bytes());
+        let i = mac.finalize().into_bytes();
+        key = i[..32].to_vec();
+        chain_code = i[32..].to_vec();
+    }
+
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&key.try_into().ok()?);
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&signing_key.to_bytes());
+    bytes[32..].copy_from_slice(&signing_key.verifying_key().to_bytes());
+    Keypair::from_bytes(&bytes).ok()
+}
+
+/// How many account indices to scan when recovering from a mnemonic.
+const SCAN_ACCOUNT_COUNT: u32 = 10;
+
+/// Builds the set of standard Solana derivation paths worth scanning: both
+/// `m/44'/501'/i'/0'` (the default used by most wallets) and the bare
+/// `m/44'/501'/i'` variant some wallets use instead, for `i` in `0..count`.
+fn standard_derivation_paths(count: u32) -> Vec<(String, Vec<u32>)> {
+    let mut paths = Vec::with_capacity((count * 2) as usize);
+    for i in 0..count {
+        paths.push((format!("m/44'/501'/{i}'/0'"), vec![44, 501, i, 0]));
+        paths.push((format!("m/44'/501'/{i}'"), vec![44, 501, i]));
+    }
+    paths
+}
+
+#[derive(Clone)]
+struct DerivedAccount {
+    path: String,
+    pubkey: Pubkey,
+    private_key_bs58: String,
+    sol_balance: AsyncResult<u64>,
+    has_proof: AsyncResult<bool>,
+}
+
 #[derive(Copy, Clone)]
 pub enum ImportKeyStep {
     Loading,
@@ -115,12 +178,21 @@ fn ImportKeyHeader() -> Element {
 
 const KEY_LENGTH: usize = 64;
 
+#[derive(Copy, Clone, PartialEq)]
+enum ImportInputMode {
+    PrivateKey,
+    RecoveryPhrase,
+}
+
 fn ImportKeyImport() -> Element {
     let mut sol_balance = use_signal::<Option<AsyncResult<u64>>>(|| None);
     let mut keypair_persistent = use_keypair_persistent();
     let mut err_msg = use_signal::<Option<String>>(|| None);
     let mut enable_import_button = use_signal(|| false);
     let mut private_key_input = use_signal(|| "".to_string());
+    let mut input_mode = use_signal(|| ImportInputMode::PrivateKey);
+    let mut derived_accounts = use_signal::<Vec<DerivedAccount>>(Vec::new);
+    let mut selected_account = use_signal::<Option<usize>>(|| None);
     let gateway = use_gateway();
     let nav = navigator();
 
@@ -131,29 +203,87 @@ fn ImportKeyImport() -> Element {
         // let err_msg = err_msg.clone();
         let gateway = gateway.clone();
         async move {
-            if let Ok(bytes) = bs58::decode(private_key_input.read().clone()).into_vec() {
-                if bytes.len().eq(&KEY_LENGTH) {
-                    if let Ok(kp) = Keypair::from_bytes(&bytes) {
-                        enable_import_button.set(true);
-                        match gateway.rpc.get_balance(&kp.pubkey()).await {
-                            Ok(b) => {
-                                sol_balance.set(Some(AsyncResult::Ok(b)));
-                            }
-                            Err(err) => {
-                                sol_balance.set(Some(AsyncResult::Error(GatewayError::from(err))));
+            match *input_mode.read() {
+                ImportInputMode::PrivateKey => {
+                    if let Ok(bytes) = bs58::decode(private_key_input.read().clone()).into_vec() {
+                        if bytes.len().eq(&KEY_LENGTH) {
+                            if let Ok(kp) = Keypair::from_bytes(&bytes) {
+                                enable_import_button.set(true);
+                                match gateway.rpc.get_balance(&kp.pubkey()).await {
+                                    Ok(b) => {
+                                        sol_balance.set(Some(AsyncResult::Ok(b)));
+                                    }
+                                    Err(err) => {
+                                        sol_balance
+                                            .set(Some(AsyncResult::Error(GatewayError::from(err))));
+                                    }
+                                }
                             }
+                        } else if bytes.len().eq(&0) {
+                            enable_import_button.set(false);
+                            err_msg.set(None);
+                        } else {
+                            enable_import_button.set(false);
+                            err_msg.set(Some("Invalid length".to_string()));
                         }
+                    } else {
+                        enable_import_button.set(false);
+                        err_msg.set(Some("Invalid format".to_string()));
                     }
-                } else if bytes.len().eq(&0) {
+                }
+                ImportInputMode::RecoveryPhrase => {
+                    let phrase = private_key_input.read().clone();
+                    derived_accounts.set(vec![]);
+                    selected_account.set(None);
                     enable_import_button.set(false);
+
+                    if phrase.trim().is_empty() {
+                        err_msg.set(None);
+                        return;
+                    }
+
+                    if keypair_from_mnemonic(&phrase, "", &DEFAULT_DERIVATION_PATH).is_none() {
+                        err_msg.set(Some("Invalid recovery phrase".to_string()));
+                        return;
+                    }
                     err_msg.set(None);
-                } else {
-                    enable_import_button.set(false);
-                    err_msg.set(Some("Invalid length".to_string()));
+
+                    let accounts: Vec<DerivedAccount> = standard_derivation_paths(SCAN_ACCOUNT_COUNT)
+                        .into_iter()
+                        .filter_map(|(path, indices)| {
+                            let kp = keypair_from_mnemonic(&phrase, "", &indices)?;
+                            Some(DerivedAccount {
+                                path,
+                                pubkey: kp.pubkey(),
+                                private_key_bs58: bs58::encode(kp.to_bytes()).into_string(),
+                                sol_balance: AsyncResult::Loading,
+                                has_proof: AsyncResult::Loading,
+                            })
+                        })
+                        .collect();
+                    let pubkeys: Vec<Pubkey> = accounts.iter().map(|a| a.pubkey).collect();
+                    derived_accounts.set(accounts);
+
+                    // Scan every derived account's balance and proof presence in
+                    // parallel, writing each result back into `derived_accounts`
+                    // by index as it resolves so the picker fills in live.
+                    join_all(pubkeys.into_iter().enumerate().map(|(i, pubkey)| {
+                        let gateway = gateway.clone();
+                        async move {
+                            let sol_balance = match gateway.rpc.get_balance(&pubkey).await {
+                                Ok(b) => AsyncResult::Ok(b),
+                                Err(err) => AsyncResult::Error(GatewayError::from(err)),
+                            };
+                            let has_proof =
+                                AsyncResult::Ok(gateway.get_proof(&pubkey).await.is_ok());
+                            if let Some(account) = derived_accounts.write().get_mut(i) {
+                                account.sol_balance = sol_balance;
+                                account.has_proof = has_proof;
+                            }
+                        }
+                    }))
+                    .await;
                 }
-            } else {
-                enable_import_button.set(false);
-                err_msg.set(Some("Invalid format".to_string()));
             }
         }
     });
@@ -165,12 +295,34 @@ fn ImportKeyImport() -> Element {
             EyeSlashIcon {
                 class: "w-12 h-12 mx-auto opacity-50"
             }
+            div {
+                class: "flex flex-row gap-2 mx-auto text-sm",
+                button {
+                    class: if *input_mode.read() == ImportInputMode::PrivateKey { "font-semibold underline" } else { "text-gray-300 dark:text-gray-700" },
+                    onclick: move |_| {
+                        input_mode.set(ImportInputMode::PrivateKey);
+                        private_key_input.set("".to_string());
+                        err_msg.set(None);
+                    },
+                    "Private key"
+                }
+                span { "·" }
+                button {
+                    class: if *input_mode.read() == ImportInputMode::RecoveryPhrase { "font-semibold underline" } else { "text-gray-300 dark:text-gray-700" },
+                    onclick: move |_| {
+                        input_mode.set(ImportInputMode::RecoveryPhrase);
+                        private_key_input.set("".to_string());
+                        err_msg.set(None);
+                    },
+                    "Recovery phrase"
+                }
+            }
             div {
                 class: "flex flex-col gap-2",
                 input {
                     class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
                     autofocus: true,
-                    placeholder: "Private key",
+                    placeholder: if *input_mode.read() == ImportInputMode::PrivateKey { "Private key" } else { "12 or 24 word recovery phrase" },
                     value: "{*private_key_input.read()}",
                     oninput: move |e| {
                         private_key_input.set(e.value());
@@ -183,30 +335,73 @@ fn ImportKeyImport() -> Element {
                     }
                 }
             }
-            if let Some(sol_balance) = *sol_balance.read() {
-                match sol_balance {
-                    AsyncResult::Loading => {
-                        rsx! {
-                            div {
-                                class: "flex flex-row w-24 h-16 loading rounded-full",
+            if *input_mode.read() == ImportInputMode::PrivateKey {
+                if let Some(sol_balance) = *sol_balance.read() {
+                    match sol_balance {
+                        AsyncResult::Loading => {
+                            rsx! {
+                                div {
+                                    class: "flex flex-row w-24 h-16 loading rounded-full",
+                                }
+                            }
+                        }
+                        AsyncResult::Ok(sol_balance) => {
+                            rsx! {
+                                p {
+                                    class: "text-nowrap mx-auto text-center font-semibold",
+                                    "Balance: {lamports_to_sol(sol_balance)} SOL"
+                                }
                             }
                         }
+                        _ => None
                     }
-                    AsyncResult::Ok(sol_balance) => {
-                        rsx! {
-                            p {
-                                class: "text-nowrap mx-auto text-center font-semibold",
-                                "Balance: {lamports_to_sol(sol_balance)} SOL"
+                }
+            } else if !derived_accounts.read().is_empty() {
+                rsx! {
+                    div {
+                        class: "flex flex-col gap-2 overflow-y-auto max-h-64",
+                        for (i , account) in derived_accounts.read().iter().enumerate() {
+                            button {
+                                key: "{account.path}",
+                                class: if selected_account.read().is_some_and(|s| s == i) { "flex flex-row justify-between gap-2 px-3 py-2 rounded bg-green-500 text-white text-sm" } else { "flex flex-row justify-between gap-2 px-3 py-2 rounded hover:bg-gray-100 dark:hover:bg-gray-900 text-sm" },
+                                onclick: move |_| selected_account.set(Some(i)),
+                                span {
+                                    class: "font-mono truncate",
+                                    "{account.pubkey}"
+                                }
+                                span {
+                                    class: "flex flex-row gap-2 shrink-0",
+                                    match account.sol_balance {
+                                        AsyncResult::Ok(b) => rsx! { span { "{lamports_to_sol(b)} SOL" } },
+                                        AsyncResult::Loading => rsx! { span { "..." } },
+                                        _ => rsx! { span { "?" } },
+                                    }
+                                    if matches!(account.has_proof, AsyncResult::Ok(true)) {
+                                        span { class: "opacity-75", "ORE proof" }
+                                    }
+                                }
                             }
                         }
                     }
-                    _ => None
                 }
             }
             button {
-                disabled: !*enable_import_button.read(),
+                disabled: match *input_mode.read() {
+                    ImportInputMode::PrivateKey => !*enable_import_button.read(),
+                    ImportInputMode::RecoveryPhrase => selected_account.read().is_none(),
+                },
                 onclick: move |_| {
-                    keypair_persistent.set(private_key_input.read().clone());
+                    match *input_mode.read() {
+                        ImportInputMode::PrivateKey => {
+                            keypair_persistent.set(private_key_input.read().clone());
+                        }
+                        ImportInputMode::RecoveryPhrase => {
+                            if let Some(i) = *selected_account.read() {
+                                keypair_persistent
+                                    .set(derived_accounts.read()[i].private_key_bs58.clone());
+                            }
+                        }
+                    }
                     nav.push(Route::Settings {});
                 },
                 class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
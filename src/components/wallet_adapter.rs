@@ -1,15 +1,105 @@
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::transaction::Transaction;
+use solana_client_wasm::solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, message::Message,
+    pubkey::Pubkey, transaction::Transaction,
+};
 
 use crate::components::{icons::CheckCircleIcon, Appearance, Spinner};
-use crate::hooks::{use_appearance, use_wallet_adapter, use_wallet_adapter::InvokeSignatureStatus};
+use crate::gateway::Gateway;
+use crate::hooks::{
+    use_appearance, use_gateway, use_wallet_adapter, use_wallet_adapter::InvokeSignatureStatus,
+};
+
+/// Priority-fee presets shown wherever a transaction is submitted, expressed
+/// as a multiplier over the network's recent average non-zero prioritization
+/// fee.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FeePreset {
+    Normal,
+    Fast,
+    Turbo,
+}
+
+impl FeePreset {
+    pub const ALL: [FeePreset; 3] = [FeePreset::Normal, FeePreset::Fast, FeePreset::Turbo];
+
+    fn multiplier(&self) -> u64 {
+        match self {
+            FeePreset::Normal => 1,
+            FeePreset::Fast => 2,
+            FeePreset::Turbo => 4,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeePreset::Normal => "Normal",
+            FeePreset::Fast => "Fast",
+            FeePreset::Turbo => "Turbo",
+        }
+    }
+}
+
+/// Compute unit limit assumed when estimating fees. Generous relative to the
+/// simple claim/mine instructions this app submits.
+const COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// Base, non-negotiable lamport cost of a single-signature transaction.
+const BASE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Queries the average non-zero prioritization fee paid over roughly the last
+/// 150 slots and scales it by `preset` to get a compute unit price in
+/// micro-lamports.
+pub async fn priority_fee_micro_lamports(gateway: &Gateway, preset: FeePreset) -> u64 {
+    let recent_fees = gateway
+        .rpc
+        .get_recent_prioritization_fees(&[])
+        .await
+        .unwrap_or_default();
+    let non_zero: Vec<u64> = recent_fees
+        .iter()
+        .map(|fee| fee.prioritization_fee)
+        .filter(|fee| *fee > 0)
+        .collect();
+    let average = if non_zero.is_empty() {
+        1
+    } else {
+        non_zero.iter().sum::<u64>() / non_zero.len() as u64
+    };
+    average.saturating_mul(preset.multiplier())
+}
+
+/// Prepends `set_compute_unit_limit` and `set_compute_unit_price` instructions
+/// to `instructions` so the resulting transaction carries a priority fee.
+pub fn with_compute_budget(
+    instructions: &[Instruction],
+    compute_unit_price: u64,
+) -> Vec<Instruction> {
+    let mut out = Vec::with_capacity(instructions.len() + 2);
+    out.push(ComputeBudgetInstruction::set_compute_unit_limit(
+        COMPUTE_UNIT_LIMIT,
+    ));
+    out.push(ComputeBudgetInstruction::set_compute_unit_price(
+        compute_unit_price,
+    ));
+    out.extend_from_slice(instructions);
+    out
+}
+
+/// Estimated total transaction fee in lamports for a given compute unit
+/// price, covering the base signature fee plus the priority fee.
+pub fn estimated_fee_lamports(compute_unit_price: u64) -> u64 {
+    let priority_fee = (compute_unit_price as u128 * COMPUTE_UNIT_LIMIT as u128) / 1_000_000;
+    BASE_FEE_LAMPORTS + priority_fee as u64
+}
 
 #[component]
 pub fn MountWalletAdapter() -> Element {
     let appearance = use_appearance();
     let wallet_adapter = use_wallet_adapter::use_wallet_adapter();
     let button_color = match *wallet_adapter.read() {
-        use_wallet_adapter::WalletAdapter::Connected(_) => match *appearance.read() {
+        use_wallet_adapter::WalletAdapter::Connected(_)
+        | use_wallet_adapter::WalletAdapter::Hardware(_) => match *appearance.read() {
             Appearance::Light => "text-black hover:bg-gray-100 active:bg-gray-200",
             Appearance::Dark => "text-white hover:bg-gray-900 active:bg-gray-800",
         },
@@ -40,19 +130,51 @@ pub fn MountWalletAdapter() -> Element {
 
 #[component]
 pub fn InvokeSignature(
-    tx: Transaction,
+    instructions: Vec<Instruction>,
+    payer: Pubkey,
+    compute_unit_price: u64,
     signal: Signal<InvokeSignatureStatus>,
     start_msg: String,
 ) -> Element {
+    let gateway = use_gateway();
+    let wallet_adapter = use_wallet_adapter::use_wallet_adapter();
+    let is_hardware = matches!(
+        *wallet_adapter.read(),
+        use_wallet_adapter::WalletAdapter::Hardware(_)
+    );
+
+    // Builds the transaction here (rather than accepting a pre-built one) so
+    // every submission through this component carries a compute budget sized
+    // by `compute_unit_price`, and always against a fresh blockhash.
+    let submit = move || {
+        let gateway = gateway.clone();
+        let instructions = instructions.clone();
+        spawn(async move {
+            signal.set(InvokeSignatureStatus::Waiting);
+            let Ok(blockhash) = gateway.rpc.get_latest_blockhash().await else {
+                signal.set(InvokeSignatureStatus::DoneWithError);
+                return;
+            };
+            let budgeted = with_compute_budget(&instructions, compute_unit_price);
+            let mut message = Message::new(&budgeted, Some(&payer));
+            message.recent_blockhash = blockhash;
+            let tx = Transaction::new_unsigned(message);
+
+            if is_hardware {
+                use_wallet_adapter::invoke_signature_hardware(tx, signal);
+            } else {
+                use_wallet_adapter::invoke_signature(tx, signal);
+            }
+        });
+    };
+
     let button_class = "w-full py-3 rounded font-semibold transition-colors text-white bg-green-500 hover:bg-green-600 active:enabled:bg-green-700";
     let e = match *signal.read() {
         InvokeSignatureStatus::Start => {
             rsx! {
                 button {
                     class: "{button_class}",
-                    onclick: move |_| {
-                        use_wallet_adapter::invoke_signature(tx.clone(), signal);
-                    },
+                    onclick: move |_| submit(),
                     "{start_msg}"
                 }
             }
@@ -66,6 +188,64 @@ pub fn InvokeSignature(
                 }
             }
         }
+        InvokeSignatureStatus::WaitingForDevice => {
+            rsx! {
+                button {
+                    class: "{button_class}",
+                    disabled: true,
+                    div {
+                        class: "flex flex-row gap-2 mx-auto items-center",
+                        Spinner {}
+                        span { "Confirm on device..." }
+                    }
+                }
+            }
+        }
+        InvokeSignatureStatus::Submitted(_) => {
+            rsx! {
+                button {
+                    class: "{button_class}",
+                    disabled: true,
+                    div {
+                        class: "flex flex-row gap-2 mx-auto items-center",
+                        Spinner {}
+                        span { "Confirming..." }
+                    }
+                }
+            }
+        }
+        InvokeSignatureStatus::Dropped => {
+            // Blockhash expired before the transaction landed. The adapter is
+            // already rebuilding and resubmitting with a fresh blockhash, up
+            // to a small retry count, so just keep showing progress.
+            rsx! {
+                button {
+                    class: "{button_class}",
+                    disabled: true,
+                    div {
+                        class: "flex flex-row gap-2 mx-auto items-center",
+                        Spinner {}
+                        span { "Transaction expired, retrying..." }
+                    }
+                }
+            }
+        }
+        InvokeSignatureStatus::ProgramError(ref err) => {
+            rsx! {
+                div {
+                    class: "flex flex-col gap-4",
+                    p {
+                        class: "mx-auto text-sm font-medium text-red-500",
+                        "{err}"
+                    }
+                    button {
+                        class: "{button_class}",
+                        onclick: move |_| submit(),
+                        "Retry"
+                    }
+                }
+            }
+        }
         InvokeSignatureStatus::DoneWithError => {
             // TODO: could add reset button here
             // or other signal to user
@@ -78,15 +258,26 @@ pub fn InvokeSignature(
                     }
                     button {
                         class: "{button_class}",
-                        onclick: move |_| {
-                            use_wallet_adapter::invoke_signature(tx.clone(), signal);
-                        },
+                        onclick: move |_| submit(),
                         // "{start_msg}"
                         "Retry"
                     }
                 }
             }
         }
+        InvokeSignatureStatus::Confirmed(_) => {
+            rsx! {
+                button {
+                    class: "{button_class}",
+                    disabled: true,
+                    div {
+                        class: "flex flex-row gap-2 mx-auto items-center",
+                        CheckCircleIcon { class: "h-5 w-5" }
+                        span { "Finalizing..." }
+                    }
+                }
+            }
+        }
         InvokeSignatureStatus::Done(sig) => {
             rsx! {
                 button {
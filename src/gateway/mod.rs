@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use gloo_timers::future::TimeoutFuture;
+use solana_client_wasm::{
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    ClientError, WasmClient,
+};
+use solana_transaction_status::TransactionConfirmationStatus;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::components::wallet_adapter::with_compute_budget;
+
+/// How many times `Gateway::sign_and_confirm` will rebuild and resubmit a
+/// transaction whose blockhash expires before it lands.
+const MAX_RETRIES: u32 = 3;
+/// How many times it polls `get_signature_statuses` per submission attempt.
+const MAX_POLLS: u32 = 60;
+/// Delay between polls.
+const POLL_INTERVAL_MS: u32 = 500;
+
+/// Thin wrapper around the wasm RPC client plus the user's locally imported
+/// signer. Used by flows (like claiming) that sign directly with a keypair
+/// held by the app, as an alternative to the browser/hardware wallet adapter.
+#[derive(Clone)]
+pub struct Gateway {
+    pub rpc: WasmClient,
+    pub keypair: Rc<Keypair>,
+}
+
+/// Generic loading/ok/error wrapper for data fetched over RPC.
+#[derive(Copy, Clone)]
+pub enum AsyncResult<T: Copy> {
+    Loading,
+    Ok(T),
+    Error(GatewayError),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum GatewayError {
+    RequestFailed,
+    AccountNotFound,
+}
+
+impl From<ClientError> for GatewayError {
+    fn from(_err: ClientError) -> Self {
+        GatewayError::RequestFailed
+    }
+}
+
+/// PDA of the proof account ORE derives from a miner's authority pubkey.
+fn proof_pubkey(authority: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ore::PROOF, authority.as_ref()], &ore::ID).0
+}
+
+impl Gateway {
+    /// Returns `Ok` if a proof account exists for `authority`, i.e. they have
+    /// mined with this wallet before.
+    pub async fn get_proof(&self, authority: &Pubkey) -> Result<ore::state::Proof, GatewayError> {
+        let data = self
+            .rpc
+            .get_account_data(&proof_pubkey(*authority))
+            .await
+            .map_err(|_| GatewayError::AccountNotFound)?;
+        ore::state::Proof::try_from_bytes(&data)
+            .map(|proof| *proof)
+            .map_err(|_| GatewayError::AccountNotFound)
+    }
+
+    /// Claims `amount` of mined ORE to the signer's associated token account.
+    ///
+    /// Prepends a compute budget sized by `compute_unit_price` (micro-lamports
+    /// per compute unit) so the claim lands promptly under congestion, and
+    /// confirms it through `sign_and_confirm`, which counts `confirmed`
+    /// commitment as success so a slow-to-finalize claim isn't reported as a
+    /// failure.
+    pub async fn claim_ore(
+        &self,
+        amount: u64,
+        compute_unit_price: u64,
+    ) -> Result<Signature, GatewayError> {
+        let signer = self.keypair.pubkey();
+        let beneficiary = get_associated_token_address(&signer, &ore::MINT_ADDRESS);
+        let ix = ore::instruction::claim(signer, beneficiary, amount);
+        let instructions = with_compute_budget(&[ix], compute_unit_price);
+        self.sign_and_confirm(&instructions).await
+    }
+
+    /// Signs `instructions` with this gateway's keypair, submits the
+    /// resulting transaction, and polls until it reaches `confirmed`
+    /// commitment. If the blockhash expires before that happens, rebuilds
+    /// and resubmits against a fresh one up to `MAX_RETRIES` times. Any
+    /// program error the transaction lands with is decoded and logged.
+    async fn sign_and_confirm(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<Signature, GatewayError> {
+        let signer = self.keypair.pubkey();
+        let mut blockhash = self
+            .rpc
+            .get_latest_blockhash()
+            .await
+            .map_err(GatewayError::from)?;
+        let mut tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signer),
+            &[self.keypair.as_ref()],
+            blockhash,
+        );
+        let mut signature = tx.signatures[0];
+
+        for attempt in 0..=MAX_RETRIES {
+            self.rpc
+                .send_transaction(&tx)
+                .await
+                .map_err(GatewayError::from)?;
+
+            for _ in 0..MAX_POLLS {
+                if let Ok(statuses) = self.rpc.get_signature_statuses(&[signature]).await {
+                    if let Some(Some(status)) = statuses.value.first() {
+                        if let Some(err) = &status.err {
+                            log::error!("Claim failed: {}", decode_program_error(err));
+                            return Err(GatewayError::RequestFailed);
+                        }
+                        if matches!(
+                            status.confirmation_status.as_ref(),
+                            Some(
+                                TransactionConfirmationStatus::Confirmed
+                                    | TransactionConfirmationStatus::Finalized
+                            )
+                        ) {
+                            return Ok(signature);
+                        }
+                    }
+                }
+                TimeoutFuture::new(POLL_INTERVAL_MS).await;
+            }
+
+            // Didn't land within MAX_POLLS. If the blockhash is still valid
+            // it may yet land, but we've exhausted our patience, so fail;
+            // otherwise it's provably dropped, so rebuild and resubmit
+            // against a fresh one unless retries are exhausted.
+            let blockhash_still_valid = self
+                .rpc
+                .is_blockhash_valid(&tx.message.recent_blockhash, CommitmentConfig::processed())
+                .await
+                .unwrap_or(true);
+            if blockhash_still_valid || attempt == MAX_RETRIES {
+                break;
+            }
+
+            blockhash = self
+                .rpc
+                .get_latest_blockhash()
+                .await
+                .map_err(GatewayError::from)?;
+            tx = Transaction::new_signed_with_payer(
+                instructions,
+                Some(&signer),
+                &[self.keypair.as_ref()],
+                blockhash,
+            );
+            signature = tx.signatures[0];
+        }
+
+        Err(GatewayError::RequestFailed)
+    }
+}
+
+/// Translates a landed transaction's on-chain error into a human-readable
+/// message, decoding ORE's own error codes where possible.
+pub(crate) fn decode_program_error(err: &TransactionError) -> String {
+    if let TransactionError::InstructionError(_, InstructionError::Custom(code)) = err {
+        if let Ok(ore_err) = ore::error::OreError::try_from(*code) {
+            return ore_err.to_string();
+        }
+    }
+    format!("{err:?}")
+}
@@ -0,0 +1,4 @@
+mod use_gateway;
+pub mod use_wallet_adapter;
+
+pub use use_gateway::use_gateway;
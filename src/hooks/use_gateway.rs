@@ -0,0 +1,9 @@
+use dioxus::prelude::*;
+
+use crate::gateway::Gateway;
+
+/// Returns the app's [`Gateway`], provided at the app root from the user's
+/// persisted keypair and the configured RPC endpoint.
+pub fn use_gateway() -> Gateway {
+    use_context::<Gateway>()
+}
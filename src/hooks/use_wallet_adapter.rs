@@ -0,0 +1,226 @@
+use base64::Engine;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use solana_client_wasm::solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature,
+    transaction::Transaction,
+};
+use solana_transaction_status::TransactionConfirmationStatus;
+
+use crate::gateway::{decode_program_error, Gateway};
+use crate::hooks::use_gateway;
+
+/// How the app is currently authorized to sign transactions.
+#[derive(Clone, PartialEq)]
+pub enum WalletAdapter {
+    Disconnected,
+    Connected(Pubkey),
+    /// Connected to a Ledger-class hardware signer via the `MountWalletAdapter`
+    /// JS bridge. Transactions route through `invoke_signature_hardware`
+    /// instead of the browser software wallet.
+    Hardware(Pubkey),
+}
+
+/// The connected wallet adapter, mounted by `MountWalletAdapter`.
+pub fn use_wallet_adapter() -> Signal<WalletAdapter> {
+    use_context::<Signal<WalletAdapter>>()
+}
+
+#[derive(Clone, PartialEq)]
+pub enum InvokeSignatureStatus {
+    Start,
+    Waiting,
+    /// Awaiting the user's physical confirmation on a connected hardware
+    /// signer.
+    WaitingForDevice,
+    /// Sent to the cluster; not yet observed at any commitment level.
+    Submitted(Signature),
+    /// Reached `confirmed` commitment; still polling if the target commitment
+    /// is higher.
+    Confirmed(Signature),
+    /// Reached the target commitment (`confirmed` by default), or reached
+    /// `confirmed` before polling gave out. Terminal success state.
+    Done(Signature),
+    /// The transaction's blockhash expired before it landed.
+    Dropped,
+    /// Landed, but the program returned an error.
+    ProgramError(String),
+    /// Terminal failure: submission or resubmission failed, polling gave out
+    /// while the blockhash was still valid, or retries were exhausted.
+    DoneWithError,
+}
+
+/// Signs `tx` with the connected browser wallet via the JS bridge, submits
+/// it, then tracks it through to confirmation.
+pub fn invoke_signature(tx: Transaction, mut signal: Signal<InvokeSignatureStatus>) {
+    spawn(async move {
+        signal.set(InvokeSignatureStatus::Waiting);
+        let gateway = use_gateway();
+        match sign_and_send(&tx).await {
+            Ok(sig) => confirm_transaction(&gateway, signal, tx, sig, false, DEFAULT_COMMITMENT).await,
+            Err(_) => signal.set(InvokeSignatureStatus::DoneWithError),
+        }
+    });
+}
+
+/// Proxies `tx` to a Ledger-class hardware signer over the same
+/// `MountWalletAdapter` JS bridge, surfacing the device's "confirm on device"
+/// state, then tracks it through to confirmation like [`invoke_signature`].
+pub fn invoke_signature_hardware(tx: Transaction, mut signal: Signal<InvokeSignatureStatus>) {
+    spawn(async move {
+        signal.set(InvokeSignatureStatus::WaitingForDevice);
+        let gateway = use_gateway();
+        match sign_and_send_hardware(&tx).await {
+            Ok(sig) => confirm_transaction(&gateway, signal, tx, sig, true, DEFAULT_COMMITMENT).await,
+            Err(_) => signal.set(InvokeSignatureStatus::DoneWithError),
+        }
+    });
+}
+
+/// Hands the unsigned transaction to the browser wallet mounted by
+/// `MountWalletAdapter` and returns the resulting signature.
+async fn sign_and_send(tx: &Transaction) -> Result<Signature, ()> {
+    request_signature(tx, "signAndSendTransaction").await
+}
+
+/// Hands the unsigned transaction to a connected Ledger-class hardware
+/// signer over the same bridge, under a distinct JS entry point so the device
+/// prompt can be driven separately from a software wallet's popup.
+async fn sign_and_send_hardware(tx: &Transaction) -> Result<Signature, ()> {
+    request_signature(tx, "signAndSendTransactionHardware").await
+}
+
+async fn request_signature(tx: &Transaction, js_fn: &str) -> Result<Signature, ()> {
+    let tx_b64 = base64::engine::general_purpose::STANDARD
+        .encode(bincode::serialize(tx).map_err(|_| ())?);
+    let eval = eval(&format!(
+        r#"
+            const sig = await window.OreWalletAdapter.{js_fn}("{tx_b64}");
+            return sig;
+        "#
+    ));
+    let result = eval.await.map_err(|_| ())?;
+    result
+        .as_str()
+        .and_then(|s| s.parse::<Signature>().ok())
+        .ok_or(())
+}
+
+/// How many times we'll rebuild and resubmit a transaction whose blockhash
+/// expires before it lands.
+const MAX_RETRIES: u32 = 3;
+/// How many times we poll `get_signature_statuses` per submission attempt.
+const MAX_POLLS: u32 = 60;
+/// Delay between polls.
+const POLL_INTERVAL_MS: u32 = 500;
+/// Commitment level at which callers consider a transaction landed by
+/// default. `Confirmed` is enough for the UI to treat a claim/mine as
+/// successful without waiting out `Finalized`'s extra latency.
+const DEFAULT_COMMITMENT: TransactionConfirmationStatus = TransactionConfirmationStatus::Confirmed;
+
+/// Ranks commitment levels so they can be compared with `>=`.
+fn commitment_rank(status: &TransactionConfirmationStatus) -> u8 {
+    match status {
+        TransactionConfirmationStatus::Processed => 0,
+        TransactionConfirmationStatus::Confirmed => 1,
+        TransactionConfirmationStatus::Finalized => 2,
+    }
+}
+
+/// Drives `signal` through `Submitted` -> `Confirmed` -> `Done` as `signature`
+/// is observed on-chain, resolving to `Done` once `target_commitment` is
+/// reached, retrying with a fresh blockhash up to `MAX_RETRIES` times if it's
+/// dropped, and decoding any program error it lands with.
+async fn confirm_transaction(
+    gateway: &Gateway,
+    mut signal: Signal<InvokeSignatureStatus>,
+    mut tx: Transaction,
+    mut signature: Signature,
+    hardware: bool,
+    target_commitment: TransactionConfirmationStatus,
+) {
+    for attempt in 0..=MAX_RETRIES {
+        signal.set(InvokeSignatureStatus::Submitted(signature));
+        let mut reached_confirmed = false;
+
+        for _ in 0..MAX_POLLS {
+            if let Ok(statuses) = gateway.rpc.get_signature_statuses(&[signature]).await {
+                if let Some(Some(status)) = statuses.value.first() {
+                    if let Some(err) = &status.err {
+                        signal.set(InvokeSignatureStatus::ProgramError(decode_program_error(
+                            err,
+                        )));
+                        return;
+                    }
+                    if let Some(status_level) = status.confirmation_status.as_ref() {
+                        if matches!(status_level, TransactionConfirmationStatus::Confirmed)
+                            && !reached_confirmed
+                        {
+                            reached_confirmed = true;
+                            signal.set(InvokeSignatureStatus::Confirmed(signature));
+                        }
+                        if commitment_rank(status_level) >= commitment_rank(&target_commitment) {
+                            signal.set(InvokeSignatureStatus::Done(signature));
+                            return;
+                        }
+                    }
+                }
+            }
+            TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        }
+
+        // The transaction didn't reach `target_commitment` within
+        // MAX_POLLS. A transaction that at least reached `confirmed` has
+        // landed -- resubmitting now would risk a double-claim, so treat it
+        // as a (slow) success rather than falling through to failure.
+        if reached_confirmed {
+            signal.set(InvokeSignatureStatus::Done(signature));
+            return;
+        }
+
+        // It never even reached `confirmed`. If its blockhash is still
+        // valid it may yet land, but we've exhausted our patience, so just
+        // fail; otherwise it's provably dropped and worth rebuilding against
+        // a fresh blockhash, unless retries are exhausted.
+        let blockhash_still_valid = gateway
+            .rpc
+            .is_blockhash_valid(&tx.message.recent_blockhash, CommitmentConfig::processed())
+            .await
+            .unwrap_or(true);
+        if blockhash_still_valid || attempt == MAX_RETRIES {
+            break;
+        }
+
+        signal.set(InvokeSignatureStatus::Dropped);
+        match resubmit_with_fresh_blockhash(gateway, &tx, hardware).await {
+            Ok((new_tx, new_sig)) => {
+                tx = new_tx;
+                signature = new_sig;
+            }
+            Err(_) => break,
+        }
+    }
+
+    signal.set(InvokeSignatureStatus::DoneWithError);
+}
+
+/// Rebuilds `tx` against the cluster's current blockhash and re-signs it
+/// through the same path (browser wallet or hardware signer) it was
+/// originally submitted with.
+async fn resubmit_with_fresh_blockhash(
+    gateway: &Gateway,
+    tx: &Transaction,
+    hardware: bool,
+) -> Result<(Transaction, Signature), ()> {
+    let blockhash = gateway.rpc.get_latest_blockhash().await.map_err(|_| ())?;
+    let mut fresh_tx = tx.clone();
+    fresh_tx.message.recent_blockhash = blockhash;
+
+    let signature = if hardware {
+        sign_and_send_hardware(&fresh_tx).await?
+    } else {
+        sign_and_send(&fresh_tx).await?
+    };
+
+    Ok((fresh_tx, signature))
+}